@@ -8,6 +8,63 @@ use std::{
 ///
 /// This is the primary way [`View`](crate::View)s communicate with each other, see
 /// [`View::message`](crate::View::message) for more information.
+///
+/// # Sending
+///
+/// Any `Send + 'static` type can be sent as a message, the `Send` bound allows messages to be
+/// created on any thread. Messages can be sent:
+/// - From a [`View`](crate::View), by returning [`Action::message`](crate::Action::message).
+/// - From a future, by returning it from [`Action::spawn`](crate::Action::spawn).
+/// - From any thread, with [`Proxy::message`](crate::Proxy::message).
+///
+/// The messages of a single [`Action`](crate::Action) are sent in order. Implementations of
+/// [`Proxy`](crate::Proxy) are required to deliver messages sent from a single thread in the
+/// order they were sent, including messages sent through clones of the same proxy. No ordering
+/// is guaranteed between different threads.
+///
+/// # Matching
+///
+/// A [`Message`] carries a single type erased item, which is matched by type:
+/// - [`Message::is`] checks the type of the item without accessing it.
+/// - [`Message::get`] and [`Message::get_mut`] borrow the item, leaving it for other views.
+/// - [`Message::take`] takes the item if it is targeted at a given [`ViewId`].
+/// - [`Message::take_untargeted`] takes the item regardless of its target.
+///
+/// Taking the item marks the message as handled, after which [`Message::is_taken`] returns
+/// `true` and propagation stops.
+///
+/// ```
+/// # use ori::{Message, ViewId};
+/// struct Increment(u32);
+///
+/// let id = ViewId::next();
+/// let mut message = Message::new(Increment(2), id);
+///
+/// assert!(message.is::<Increment>());
+/// assert!(message.get::<String>().is_none());
+///
+/// if let Some(Increment(amount)) = message.get_mut() {
+///     *amount += 1;
+/// }
+///
+/// // taking with the wrong target leaves the item in place
+/// assert!(message.take::<Increment>(ViewId::next()).is_none());
+/// assert!(!message.is_taken());
+///
+/// let Some(Increment(amount)) = message.take(id) else {
+///     unreachable!()
+/// };
+///
+/// assert_eq!(amount, 3);
+/// assert!(message.is_taken());
+///
+/// // the target is ignored when taking untargeted
+/// let mut message = Message::new(Increment(1), ViewId::next());
+///
+/// assert!(message.take_untargeted::<String>().is_none());
+/// assert!(matches!(message.take_untargeted(), Some(Increment(1))));
+/// assert!(message.get::<Increment>().is_none());
+/// ```
 pub struct Message {
     target:    Option<ViewId>,
     item:      Option<Box<dyn Any + Send>>,
@@ -17,6 +74,10 @@ pub struct Message {
 
 impl Message {
     /// Create a new [`Message`] with over an `item` and an optional `target`.
+    ///
+    /// A message without a `target` is broadcast to the whole [`View`](crate::View) tree. A
+    /// targeted message skips the branches of [`ViewSeq`](crate::ViewSeq)s that do not contain
+    /// `target`, see [`ViewId`].
     pub fn new<T: Any + Send>(item: T, target: impl Into<Option<ViewId>>) -> Self {
         Self {
             target:    target.into(),
@@ -121,6 +182,16 @@ impl fmt::Debug for Message {
 }
 
 /// Unique key for targeting [`Message`]s.
+///
+/// A [`View`](crate::View) that wants to receive targeted messages creates a [`ViewId`], usually
+/// with [`ViewId::next`], and registers it with [`Tracker::register`](crate::Tracker::register)
+/// when built, and unregisters it when torn down.
+///
+/// Targeted messages skip the branches of [`ViewSeq`](crate::ViewSeq)s that do not contain the
+/// target. Views outside of sequences, e.g. the root and any wrapping views, still receive the
+/// message, and can take it with [`Message::take`] if they match the target. If no view has
+/// registered the target, e.g. because it was torn down, every sequence branch is skipped, but
+/// views outside of sequences still see the message.
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ViewId {
@@ -166,3 +237,89 @@ impl ViewId {
         Self { data: data as i64 }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::Pin,
+        sync::{Arc, mpsc},
+        thread,
+    };
+
+    use super::*;
+    use crate::{Action, Proxy, Tracker, Tree, ViewSeq, views};
+
+    struct ChannelProxy(mpsc::Sender<Message>);
+
+    impl Proxy for ChannelProxy {
+        fn cloned(&self) -> Arc<dyn Proxy> {
+            Arc::new(ChannelProxy(self.0.clone()))
+        }
+
+        fn rebuild(&self) {}
+
+        fn message(&self, message: Message) {
+            let _ = self.0.send(message);
+        }
+
+        fn spawn_boxed(&self, _future: Pin<Box<dyn Future<Output = ()> + Send>>) {}
+    }
+
+    #[derive(Default)]
+    struct Context {
+        tree: Tree,
+    }
+
+    impl Tracker for Context {
+        fn tree(&mut self) -> &mut Tree {
+            &mut self.tree
+        }
+    }
+
+    struct Command(u32);
+
+    fn deliver<V>(view: V, messages: impl IntoIterator<Item = Message>) -> Vec<u32>
+    where
+        V: ViewSeq<Context, Vec<u32>, ()>,
+    {
+        let mut cx = Context::default();
+        let mut data = Vec::new();
+        let mut state = view.seq_build(&mut (), &mut cx, &mut data);
+
+        for mut message in messages {
+            let _ = V::seq_message(
+                &mut (),
+                &mut state,
+                &mut cx,
+                &mut data,
+                &mut message,
+            );
+        }
+
+        data
+    }
+
+    #[test]
+    fn message_from_thread() {
+        let id = ViewId::next();
+        let (sender, receiver) = mpsc::channel();
+        let proxy = ChannelProxy(sender).cloned();
+
+        thread::spawn(move || {
+            proxy.message(Message::new(Command(7), id));
+        })
+        .join()
+        .expect("sending thread should not panic");
+
+        let view = views::context(move |_, cx: &mut Context| {
+            cx.register(id);
+
+            views::receive(id, |data: &mut Vec<u32>, Command(n)| {
+                data.push(n);
+                Action::new()
+            })
+        });
+
+        assert_eq!(deliver(view, receiver.try_iter()), [7]);
+    }
+}
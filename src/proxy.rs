@@ -8,6 +8,9 @@ pub trait Proxied {
     type Proxy: Proxy;
 
     /// Create a [`Self::Proxy`].
+    ///
+    /// The returned proxy stays valid after the context is dropped, and may be sent to other
+    /// threads.
     fn proxy(&mut self) -> Self::Proxy;
 
     /// Send an action using [`Self::Proxy`].
@@ -18,6 +21,14 @@ pub trait Proxied {
 }
 
 /// A proxy for [`Action`]s.
+///
+/// A [`Proxy`] is a handle to the running event loop, it is `Send + Sync` and can be shared with
+/// other threads through [`Self::cloned`]. This makes it suitable for injecting messages from
+/// external sources, e.g. a background thread or a socket.
+///
+/// Implementations of [`Self::rebuild`], [`Self::message`] and [`Self::spawn_boxed`] must wake
+/// the event loop, [`Self::cloned`] must not. No method may block waiting for the
+/// [`View`](crate::View) tree.
 pub trait Proxy: Send + Sync + 'static {
     /// Clone `self` into an [`Arc`].
     fn cloned(&self) -> Arc<dyn Proxy>;
@@ -26,6 +37,9 @@ pub trait Proxy: Send + Sync + 'static {
     fn rebuild(&self);
 
     /// Send a [`Message`] to the [`View`](crate::View) tree.
+    ///
+    /// Implementations must deliver messages sent from a single thread in the order they were
+    /// sent, this includes messages sent through clones obtained from [`Self::cloned`].
     fn message(&self, message: Message);
 
     /// Spawn a boxed future.
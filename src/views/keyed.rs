@@ -13,6 +13,13 @@ pub fn keyed<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> Keyed<K, V> {
 }
 
 /// [`ViewSeq`] that orders contents to match a list of keys.
+///
+/// Each [`View`] is identified by its key rather than its position in the sequence. When the keys
+/// are reordered, the matching [`View`]s are moved along with their state instead of being
+/// rebuilt, views with new keys are built, and views whose keys are gone are torn down.
+///
+/// A [`Memo`](crate::views::Memo) inside [`Keyed`] keeps its state when moved, and still only
+/// rebuilds its contents when its data changes.
 pub struct Keyed<K, V> {
     pairs: Vec<(K, V)>,
 }
@@ -7,6 +7,7 @@ mod freeze;
 mod keyed;
 mod maybe;
 mod memo;
+mod mount;
 mod portal;
 mod provide;
 mod receive;
@@ -21,6 +22,7 @@ pub use freeze::{Freeze, freeze};
 pub use keyed::{Keyed, keyed};
 pub use maybe::{Maybe, maybe};
 pub use memo::{Memo, memo, memo_hashed};
+pub use mount::{OnMount, OnUnmount, on_mount, on_unmount};
 pub use portal::{Portal, Teleport, portal, teleport};
 pub use provide::{Provide, Using, provide, try_using, using, using_or_default};
 pub use receive::{Receive, receive, receive_all};
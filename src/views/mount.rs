@@ -0,0 +1,231 @@
+use crate::{Action, Message, Mut, View, ViewMarker};
+
+/// [`View`] that calls `on_mount` when `contents` enter the tree.
+///
+/// `on_mount` is called once, before `contents` are built. This means that an outer `on_mount`
+/// is always called before the `on_mount` of its `contents`.
+///
+/// See [`on_unmount`] for the order of calls when contents are replaced.
+pub fn on_mount<C, T, V>(
+    contents: V,
+    on_mount: impl FnOnce(&mut T, &mut C),
+) -> impl View<C, T, Element = V::Element>
+where
+    V: View<C, T>,
+{
+    OnMount::new(contents, on_mount)
+}
+
+/// [`View`] that calls `on_unmount` when `contents` leave the tree.
+///
+/// `on_unmount` is called once, after `contents` are torn down. This means that the `on_unmount`
+/// of `contents` is always called before an outer `on_unmount`.
+///
+/// When contents are replaced, e.g. by [`any`](crate::views::any), the new contents are built
+/// before the old ones are torn down. This means that the `on_mount` of the new contents is called
+/// before the `on_unmount` of the old.
+pub fn on_unmount<C, T, V>(
+    contents: V,
+    on_unmount: impl FnOnce(&mut C),
+) -> impl View<C, T, Element = V::Element>
+where
+    V: View<C, T>,
+{
+    OnUnmount::new(contents, on_unmount)
+}
+
+/// [`View`] that calls `on_mount` when `contents` enter the tree.
+#[must_use]
+pub struct OnMount<V, F> {
+    contents: V,
+    on_mount: F,
+}
+
+impl<V, F> OnMount<V, F> {
+    /// Create new [`OnMount`].
+    pub fn new(contents: V, on_mount: F) -> Self {
+        Self { contents, on_mount }
+    }
+}
+
+impl<V, F> ViewMarker for OnMount<V, F> {}
+impl<C, T, V, F> View<C, T> for OnMount<V, F>
+where
+    V: View<C, T>,
+    F: FnOnce(&mut T, &mut C),
+{
+    type Element = V::Element;
+    type State = V::State;
+
+    fn build(self, cx: &mut C, data: &mut T) -> (Self::Element, Self::State) {
+        (self.on_mount)(data, cx);
+        self.contents.build(cx, data)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut C,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(element, state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut C,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        V::message(element, state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut C) {
+        V::teardown(element, state, cx);
+    }
+}
+
+/// [`View`] that calls `on_unmount` when `contents` leave the tree.
+#[must_use]
+pub struct OnUnmount<V, F> {
+    contents:   V,
+    on_unmount: F,
+}
+
+impl<V, F> OnUnmount<V, F> {
+    /// Create new [`OnUnmount`].
+    pub fn new(contents: V, on_unmount: F) -> Self {
+        Self {
+            contents,
+            on_unmount,
+        }
+    }
+}
+
+impl<V, F> ViewMarker for OnUnmount<V, F> {}
+impl<C, T, V, F> View<C, T> for OnUnmount<V, F>
+where
+    V: View<C, T>,
+    F: FnOnce(&mut C),
+{
+    type Element = V::Element;
+    type State = (F, V::State);
+
+    fn build(self, cx: &mut C, data: &mut T) -> (Self::Element, Self::State) {
+        let (element, state) = self.contents.build(cx, data);
+        (element, (self.on_unmount, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (on_unmount, state): &mut Self::State,
+        cx: &mut C,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(element, state, cx, data);
+        *on_unmount = self.on_unmount;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (_, state): &mut Self::State,
+        cx: &mut C,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        V::message(element, state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (on_unmount, state): Self::State, cx: &mut C) {
+        V::teardown(element, state, cx);
+        on_unmount(cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Base, views};
+
+    #[derive(Default)]
+    struct Context {
+        log: Vec<&'static str>,
+    }
+
+    impl Base for Context {
+        type Element = ();
+    }
+
+    fn logged<V>(
+        contents: V,
+        mount: &'static str,
+        unmount: &'static str,
+    ) -> impl View<Context, (), Element = ()>
+    where
+        V: View<Context, (), Element = ()>,
+    {
+        on_unmount(
+            on_mount(contents, move |_, cx: &mut Context| {
+                cx.log.push(mount)
+            }),
+            move |cx: &mut Context| cx.log.push(unmount),
+        )
+    }
+
+    fn leaf(mount: &'static str, unmount: &'static str) -> impl View<Context, (), Element = ()> {
+        logged(views::effects(()), mount, unmount)
+    }
+
+    fn build_and_teardown<V>(view: V) -> Vec<&'static str>
+    where
+        V: View<Context, ()>,
+    {
+        let mut cx = Context::default();
+        let (element, state) = view.build(&mut cx, &mut ());
+        V::teardown(element, state, &mut cx);
+        cx.log
+    }
+
+    #[test]
+    fn nested_order() {
+        let view = logged(
+            leaf("mount inner", "unmount inner"),
+            "mount outer",
+            "unmount outer",
+        );
+
+        assert_eq!(
+            build_and_teardown(view),
+            [
+                "mount outer",
+                "mount inner",
+                "unmount inner",
+                "unmount outer",
+            ],
+        );
+    }
+
+    #[test]
+    fn replaced_order() {
+        let mut cx = Context::default();
+
+        let view = views::any(leaf("mount 1", "unmount 1"));
+        let (element, mut state) = view.build(&mut cx, &mut ());
+
+        // a different type, so `any` replaces the contents
+        let view = views::any(logged(
+            leaf("mount 3", "unmount 3"),
+            "mount 2",
+            "unmount 2",
+        ));
+        view.rebuild(element, &mut state, &mut cx, &mut ());
+
+        assert_eq!(
+            cx.log,
+            ["mount 1", "mount 2", "mount 3", "unmount 1"]
+        );
+    }
+}
@@ -3,6 +3,10 @@ use std::{any::Any, marker::PhantomData};
 use crate::{Action, Message, Mut, Provider, View, ViewMarker};
 
 /// [`View`] that provides a `resource` to a [`View`], see [`using`] for how to use contexts.
+///
+/// The `resource` is only available to `contents` and their descendants, and shadows any
+/// resource of the same type provided further up the tree. Resources inserted directly into the
+/// [`Provider`] by the platform are available everywhere, and are shadowed in the same way.
 pub fn provide<C, T, U, V>(state: U, contents: V) -> impl View<C, T, Element = V::Element>
 where
     U: Any,